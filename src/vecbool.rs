@@ -1,23 +1,50 @@
 //! Module with the [VecBool] implementation
 
-/// Underlying datatype to store the bits
-type Chunk = u8;
-const CHUNK_SIZE: usize = 8;
+use std::borrow::Cow;
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not};
+
+/// Underlying datatype to store the bits. Using the native word size lets every bitwise op,
+/// `count_ones`, `set_range` and equality compare a full machine word at a time instead of a byte.
+type Chunk = usize;
+const CHUNK_SIZE: usize = usize::BITS as usize;
+
+/// Number of bits that fit inline, without allocating on the heap. [Repr] is an ordinary tagged
+/// enum, so the discriminant is stored alongside the payload rather than stolen from it — a full
+/// `Chunk` worth of bits is available inline.
+const INLINE_CAPACITY: usize = CHUNK_SIZE;
+
+/// Storage backing a [VecBool]: either a single inline [Chunk] (no heap allocation), or, once it
+/// grows past [INLINE_CAPACITY] bits, spilled onto the heap as a `Vec<Chunk>`.
+#[derive(Clone, Debug)]
+enum Repr {
+    Inline(Chunk),
+    Heap(Vec<Chunk>),
+}
 
-/// Wrapper around [Vec<u8>]. You can use it similarly to a `Vec<bool>`.
+/// Wrapper around a packed `Vec<usize>`. You can use it similarly to a `Vec<bool>`.
+///
+/// Small masks (up to `usize::BITS` bits) are stored inline and do not allocate on the heap.
+#[derive(Clone, Debug)]
 pub struct VecBool {
     len: usize,
-    chunks: Vec<Chunk>,
+    repr: Repr,
+}
+
+impl Default for VecBool {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl VecBool {
     #[inline]
     /// Creates a new empty [VecBool].
     ///
-    /// Does not allocate memory on heap until elements are added.
+    /// Does not allocate memory on heap until it grows past `INLINE_CAPACITY` bits.
     pub fn new() -> Self {
         Self {
-            chunks: Vec::new(),
+            repr: Repr::Inline(0),
             len: 0,
         }
     }
@@ -25,19 +52,25 @@ impl VecBool {
     #[inline]
     // Create a [VecBool] with preallocated memory.
     pub fn with_capacity(capacity: usize) -> Self {
-        Self {
-            chunks: Vec::with_capacity((capacity / CHUNK_SIZE) + 1),
-            len: 0,
-        }
+        let repr = if capacity <= INLINE_CAPACITY {
+            Repr::Inline(0)
+        } else {
+            Repr::Heap(Vec::with_capacity((capacity / CHUNK_SIZE) + 1))
+        };
+
+        Self { repr, len: 0 }
     }
 
     #[inline]
     // Create a [VecBool] with all bits set to `0`
     pub fn with_zeros(len: usize) -> Self {
-        Self {
-            chunks: vec![0; (len / CHUNK_SIZE) + 1],
-            len,
-        }
+        let repr = if len <= INLINE_CAPACITY {
+            Repr::Inline(0)
+        } else {
+            Repr::Heap(vec![0; Self::chunks_needed(len)])
+        };
+
+        Self { repr, len }
     }
 
     #[inline]
@@ -45,9 +78,17 @@ impl VecBool {
         self.len
     }
 
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
     #[inline]
     pub fn capacity(&self) -> usize {
-        self.chunks.len() * CHUNK_SIZE
+        match &self.repr {
+            Repr::Inline(_) => INLINE_CAPACITY,
+            Repr::Heap(chunks) => chunks.len() * CHUNK_SIZE,
+        }
     }
 
     #[inline]
@@ -65,9 +106,7 @@ impl VecBool {
     pub fn get_unchecked(&self, index: usize) -> bool {
         let (chunk_index, mask) = VecBool::get_index(index);
 
-        let bits = self.chunks[chunk_index];
-
-        (bits & mask) != 0
+        self.with_chunks(|chunks| chunks[chunk_index] & mask != 0)
     }
 
     #[inline]
@@ -87,18 +126,83 @@ impl VecBool {
     pub fn set_unchecked(&mut self, index: usize, value: bool) {
         let (chunk_index, mask) = VecBool::get_index(index);
 
+        self.with_chunks_mut(|chunks| {
+            if value {
+                chunks[chunk_index] |= mask;
+            } else {
+                chunks[chunk_index] &= !mask;
+            }
+        });
+    }
+
+    /// Set every bit in `start..end` to `value` in `O(chunks)` instead of `O(bits)`. This method
+    /// **panics** if `end` is out of bounds.
+    pub fn set_range(&mut self, start: usize, end: usize, value: bool) {
+        assert!(end <= self.len, "end out of bounds: {} > {}", end, self.len);
+
+        if start >= end {
+            return;
+        }
+
+        let head_chunk = start / CHUNK_SIZE;
+        let tail_chunk = (end - 1) / CHUNK_SIZE;
+        let start_shift = start % CHUNK_SIZE;
+        let end_shift = end % CHUNK_SIZE;
+
+        self.with_chunks_mut(|chunks| {
+            if head_chunk == tail_chunk {
+                let mask =
+                    Self::range_mask(start_shift, if end_shift == 0 { CHUNK_SIZE } else { end_shift });
+                Self::apply_mask(&mut chunks[head_chunk], mask, value);
+                return;
+            }
+
+            let head_mask = Self::range_mask(start_shift, CHUNK_SIZE);
+            Self::apply_mask(&mut chunks[head_chunk], head_mask, value);
+
+            for chunk in &mut chunks[(head_chunk + 1)..tail_chunk] {
+                *chunk = if value { Chunk::MAX } else { 0 };
+            }
+
+            if end_shift != 0 {
+                let tail_mask = Self::range_mask(0, end_shift);
+                Self::apply_mask(&mut chunks[tail_chunk], tail_mask, value);
+            } else {
+                chunks[tail_chunk] = if value { Chunk::MAX } else { 0 };
+            }
+        });
+    }
+
+    #[inline]
+    /// Builds a mask with the bits `start..end` (within a single chunk) set to `1`.
+    fn range_mask(start: usize, end: usize) -> Chunk {
+        if end >= CHUNK_SIZE {
+            Chunk::MAX << start
+        } else {
+            (Chunk::MAX << start) & !(Chunk::MAX << end)
+        }
+    }
+
+    #[inline]
+    fn apply_mask(chunk: &mut Chunk, mask: Chunk, value: bool) {
         if value {
-            self.chunks[chunk_index] |= mask;
+            *chunk |= mask;
         } else {
-            self.chunks[chunk_index] &= !mask;
+            *chunk &= !mask;
         }
     }
 
     #[inline]
     /// Push an `bool` to the end of vector.
     pub fn push(&mut self, value: bool) {
+        if matches!(self.repr, Repr::Inline(_)) && self.len == INLINE_CAPACITY {
+            self.migrate_to_heap();
+        }
+
         if self.len >= self.capacity() {
-            self.chunks.push(0)
+            if let Repr::Heap(chunks) = &mut self.repr {
+                chunks.push(0);
+            }
         }
 
         self.len += 1;
@@ -113,26 +217,131 @@ impl VecBool {
             return None;
         }
 
+        let data = self.get_unchecked(self.len - 1);
         self.len -= 1;
-        let data = self.get_unchecked(self.len);
 
-        if self.len % CHUNK_SIZE == 0 {
-            self.chunks.pop();
+        if let Repr::Heap(chunks) = &mut self.repr {
+            if self.len.is_multiple_of(CHUNK_SIZE) {
+                chunks.pop();
+            }
+        }
+
+        if matches!(self.repr, Repr::Heap(_)) && self.len <= INLINE_CAPACITY {
+            self.migrate_to_inline();
         }
 
         Some(data)
     }
 
     #[inline]
-    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
-        self.chunks
-            .iter()
-            .take(self.len / CHUNK_SIZE)
-            .flat_map(|chunk| (0..CHUNK_SIZE).map(move |shift| chunk & (1 << shift) != 0))
-            .chain({
-                let chunk = self.chunks.last().copied().unwrap_or_default();
-                (0..(self.len % CHUNK_SIZE)).map(move |shift| chunk & (1 << shift) != 0)
-            })
+    /// Moves the bits out of the inline [Chunk] and onto a heap-allocated `Vec<Chunk>`, once the
+    /// vector grows past [INLINE_CAPACITY].
+    fn migrate_to_heap(&mut self) {
+        if let Repr::Inline(bits) = self.repr {
+            self.repr = Repr::Heap(vec![bits]);
+        }
+    }
+
+    #[inline]
+    /// Number of chunks needed to hold `len` bits, with no stale trailing chunk.
+    fn chunks_needed(len: usize) -> usize {
+        let full_chunks = len / CHUNK_SIZE;
+
+        if len.is_multiple_of(CHUNK_SIZE) {
+            full_chunks
+        } else {
+            full_chunks + 1
+        }
+    }
+
+    #[inline]
+    /// Moves the bits back into the inline [Chunk], once the vector shrinks back to
+    /// [INLINE_CAPACITY] bits or fewer.
+    fn migrate_to_inline(&mut self) {
+        if let Repr::Heap(chunks) = &self.repr {
+            self.repr = Repr::Inline(chunks.first().copied().unwrap_or(0));
+        }
+    }
+
+    #[inline]
+    /// Returns an iterator over the bits, supporting [Iterator::rev] and [ExactSizeIterator::len].
+    ///
+    /// Borrows the backing chunks directly when heap-allocated, so iterating does not allocate.
+    pub fn iter(&self) -> Iter<'_> {
+        let chunks = match &self.repr {
+            Repr::Inline(bits) => {
+                let needed = Self::chunks_needed(self.len);
+                Cow::Owned(if needed == 0 { Vec::new() } else { vec![*bits] })
+            }
+            Repr::Heap(chunks) => Cow::Borrowed(chunks.as_slice()),
+        };
+
+        Iter {
+            chunks,
+            front: 0,
+            back: self.len,
+        }
+    }
+
+    #[inline]
+    /// Counts how many bits are set to `true`.
+    pub fn count_ones(&self) -> usize {
+        let full_chunks = self.len / CHUNK_SIZE;
+        let remaining_bits = self.len % CHUNK_SIZE;
+
+        self.with_chunks(|chunks| {
+            let mut ones = chunks[..full_chunks]
+                .iter()
+                .map(|chunk| chunk.count_ones() as usize)
+                .sum::<usize>();
+
+            if remaining_bits != 0 {
+                let mask = (1 << remaining_bits) - 1;
+                ones += (chunks[full_chunks] & mask).count_ones() as usize;
+            }
+
+            ones
+        })
+    }
+
+    #[inline]
+    /// Counts how many bits are set to `false`.
+    pub fn count_zeros(&self) -> usize {
+        self.len - self.count_ones()
+    }
+
+    #[inline]
+    /// Iterates over the indices of the bits set to `true`.
+    pub fn iter_ones(&self) -> impl Iterator<Item = usize> + '_ {
+        let full_chunks = self.len / CHUNK_SIZE;
+        let remaining_bits = self.len % CHUNK_SIZE;
+
+        let indices: Vec<usize> = self.with_chunks(|chunks| {
+            chunks
+                .iter()
+                .take(Self::chunks_needed(self.len))
+                .enumerate()
+                .flat_map(|(chunk_index, &chunk)| {
+                    let mut bits = if chunk_index == full_chunks && remaining_bits != 0 {
+                        chunk & ((1 << remaining_bits) - 1)
+                    } else {
+                        chunk
+                    };
+
+                    std::iter::from_fn(move || {
+                        if bits == 0 {
+                            None
+                        } else {
+                            let shift = bits.trailing_zeros() as usize;
+                            bits &= bits - 1;
+                            Some(chunk_index * CHUNK_SIZE + shift)
+                        }
+                    })
+                })
+                .collect()
+        });
+
+        indices.into_iter()
     }
 
     #[inline]
@@ -143,6 +352,313 @@ impl VecBool {
 
         (chunk_index, mask)
     }
+
+    #[inline]
+    /// Gives read access to the chunks that cover `self.len` bits, regardless of whether they are
+    /// stored inline or on the heap.
+    fn with_chunks<R>(&self, f: impl FnOnce(&[Chunk]) -> R) -> R {
+        match &self.repr {
+            Repr::Inline(bits) => {
+                let chunk = [*bits];
+                f(&chunk[..Self::chunks_needed(self.len)])
+            }
+            Repr::Heap(chunks) => f(chunks),
+        }
+    }
+
+    #[inline]
+    /// Gives mutable access to the chunks that cover `self.len` bits, regardless of whether they
+    /// are stored inline or on the heap.
+    fn with_chunks_mut<R>(&mut self, f: impl FnOnce(&mut [Chunk]) -> R) -> R {
+        match &mut self.repr {
+            Repr::Inline(bits) => {
+                let mut chunk = [*bits];
+                let result = f(&mut chunk[..Self::chunks_needed(self.len)]);
+                *bits = chunk[0];
+                result
+            }
+            Repr::Heap(chunks) => f(chunks),
+        }
+    }
+
+    #[inline]
+    /// Panics if `self` and `other` do not have the same `len`. Bitwise operations between two
+    /// [VecBool]s only make sense when both have the same length.
+    fn assert_same_len(&self, other: &VecBool) {
+        assert_eq!(
+            self.len, other.len,
+            "cannot perform bitwise operation between VecBools of different lengths ({} != {})",
+            self.len, other.len
+        );
+    }
+
+    #[inline]
+    /// Clears the unused high bits of the final valid chunk, and zeroes out any stale chunk at or
+    /// beyond [Self::chunks_needed], so they all stay canonically zero.
+    fn clear_unused_bits(&mut self) {
+        let needed = Self::chunks_needed(self.len);
+        let valid_bits = self.len % CHUNK_SIZE;
+
+        self.with_chunks_mut(|chunks| {
+            if valid_bits != 0 {
+                if let Some(last) = chunks.get_mut(needed - 1) {
+                    *last &= (1 << valid_bits) - 1;
+                }
+            }
+
+            for chunk in chunks.iter_mut().skip(needed) {
+                *chunk = 0;
+            }
+        });
+    }
+}
+
+impl BitAndAssign<&VecBool> for VecBool {
+    #[inline]
+    /// Sets each bit to `self & other`. Panics if `self.len() != other.len()`.
+    fn bitand_assign(&mut self, other: &VecBool) {
+        self.assert_same_len(other);
+
+        other.with_chunks(|other_chunks| {
+            self.with_chunks_mut(|chunks| {
+                for (chunk, other_chunk) in chunks.iter_mut().zip(other_chunks) {
+                    *chunk &= other_chunk;
+                }
+            });
+        });
+    }
+}
+
+impl BitOrAssign<&VecBool> for VecBool {
+    #[inline]
+    /// Sets each bit to `self | other`. Panics if `self.len() != other.len()`.
+    fn bitor_assign(&mut self, other: &VecBool) {
+        self.assert_same_len(other);
+
+        other.with_chunks(|other_chunks| {
+            self.with_chunks_mut(|chunks| {
+                for (chunk, other_chunk) in chunks.iter_mut().zip(other_chunks) {
+                    *chunk |= other_chunk;
+                }
+            });
+        });
+    }
+}
+
+impl BitXorAssign<&VecBool> for VecBool {
+    #[inline]
+    /// Sets each bit to `self ^ other`. Panics if `self.len() != other.len()`.
+    fn bitxor_assign(&mut self, other: &VecBool) {
+        self.assert_same_len(other);
+
+        other.with_chunks(|other_chunks| {
+            self.with_chunks_mut(|chunks| {
+                for (chunk, other_chunk) in chunks.iter_mut().zip(other_chunks) {
+                    *chunk ^= other_chunk;
+                }
+            });
+        });
+    }
+}
+
+impl BitAnd<&VecBool> for &VecBool {
+    type Output = VecBool;
+
+    #[inline]
+    /// Combines two [VecBool]s chunk-at-a-time. Panics if `self.len() != other.len()`.
+    fn bitand(self, other: &VecBool) -> VecBool {
+        let mut result = self.clone();
+        result &= other;
+        result
+    }
+}
+
+impl BitOr<&VecBool> for &VecBool {
+    type Output = VecBool;
+
+    #[inline]
+    /// Combines two [VecBool]s chunk-at-a-time. Panics if `self.len() != other.len()`.
+    fn bitor(self, other: &VecBool) -> VecBool {
+        let mut result = self.clone();
+        result |= other;
+        result
+    }
+}
+
+impl BitXor<&VecBool> for &VecBool {
+    type Output = VecBool;
+
+    #[inline]
+    /// Combines two [VecBool]s chunk-at-a-time. Panics if `self.len() != other.len()`.
+    fn bitxor(self, other: &VecBool) -> VecBool {
+        let mut result = self.clone();
+        result ^= other;
+        result
+    }
+}
+
+impl Not for &VecBool {
+    type Output = VecBool;
+
+    #[inline]
+    /// Flips every valid bit, leaving the unused high bits of the final chunk as zero.
+    fn not(self) -> VecBool {
+        let mut result = self.clone();
+        result.with_chunks_mut(|chunks| {
+            for chunk in chunks.iter_mut() {
+                *chunk = !*chunk;
+            }
+        });
+        result.clear_unused_bits();
+        result
+    }
+}
+
+impl PartialEq for VecBool {
+    #[inline]
+    /// Compares the valid bits of both [VecBool]s, ignoring the unused high bits of the final chunk.
+    fn eq(&self, other: &Self) -> bool {
+        if self.len != other.len {
+            return false;
+        }
+
+        let full_chunks = self.len / CHUNK_SIZE;
+        let remaining_bits = self.len % CHUNK_SIZE;
+
+        self.with_chunks(|a| {
+            other.with_chunks(|b| {
+                if a[..full_chunks] != b[..full_chunks] {
+                    return false;
+                }
+
+                if remaining_bits == 0 {
+                    return true;
+                }
+
+                let mask = (1 << remaining_bits) - 1;
+                (a[full_chunks] & mask) == (b[full_chunks] & mask)
+            })
+        })
+    }
+}
+
+impl Eq for VecBool {}
+
+impl FromIterator<bool> for VecBool {
+    #[inline]
+    fn from_iter<T: IntoIterator<Item = bool>>(iter: T) -> Self {
+        let mut result = VecBool::new();
+        result.extend(iter);
+        result
+    }
+}
+
+impl Extend<bool> for VecBool {
+    #[inline]
+    fn extend<T: IntoIterator<Item = bool>>(&mut self, iter: T) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl IntoIterator for VecBool {
+    type Item = bool;
+    type IntoIter = Iter<'static>;
+
+    #[inline]
+    fn into_iter(self) -> Iter<'static> {
+        let len = self.len;
+        let chunks = match self.repr {
+            Repr::Inline(bits) => {
+                if Self::chunks_needed(len) == 0 {
+                    Vec::new()
+                } else {
+                    vec![bits]
+                }
+            }
+            Repr::Heap(chunks) => chunks,
+        };
+
+        Iter {
+            chunks: Cow::Owned(chunks),
+            front: 0,
+            back: len,
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a VecBool {
+    type Item = bool;
+    type IntoIter = Iter<'a>;
+
+    #[inline]
+    fn into_iter(self) -> Iter<'a> {
+        self.iter()
+    }
+}
+
+/// Iterator over the bits of a [VecBool], returned by [VecBool::iter] and
+/// `VecBool::into_iter`. Supports [DoubleEndedIterator], so [Iterator::rev] works.
+///
+/// Borrows the chunks from a heap-backed [VecBool] (no allocation); owns them when built from an
+/// inline [VecBool] or from `VecBool::into_iter`.
+pub struct Iter<'a> {
+    chunks: Cow<'a, [Chunk]>,
+    front: usize,
+    back: usize,
+}
+
+impl Iter<'_> {
+    #[inline]
+    fn get(&self, index: usize) -> bool {
+        let (chunk_index, mask) = VecBool::get_index(index);
+
+        self.chunks[chunk_index] & mask != 0
+    }
+}
+
+impl Iterator for Iter<'_> {
+    type Item = bool;
+
+    #[inline]
+    fn next(&mut self) -> Option<bool> {
+        if self.front >= self.back {
+            return None;
+        }
+
+        let value = self.get(self.front);
+        self.front += 1;
+
+        Some(value)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for Iter<'_> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.back - self.front
+    }
+}
+
+impl DoubleEndedIterator for Iter<'_> {
+    #[inline]
+    fn next_back(&mut self) -> Option<bool> {
+        if self.front >= self.back {
+            return None;
+        }
+
+        self.back -= 1;
+
+        Some(self.get(self.back))
+    }
 }
 
 #[cfg(test)]
@@ -193,6 +709,97 @@ mod test {
         )
     }
 
+    #[test]
+    fn bitwise_ops() {
+        let mut a = VecBool::new();
+        let mut b = VecBool::new();
+        for value in [true, true, false, false] {
+            a.push(value);
+        }
+        for value in [true, false, true, false] {
+            b.push(value);
+        }
+
+        assert_eq!(
+            (&a & &b).iter().collect::<Vec<_>>(),
+            vec![true, false, false, false]
+        );
+        assert_eq!(
+            (&a | &b).iter().collect::<Vec<_>>(),
+            vec![true, true, true, false]
+        );
+        assert_eq!(
+            (&a ^ &b).iter().collect::<Vec<_>>(),
+            vec![false, true, true, false]
+        );
+        assert_eq!(
+            (!&a).iter().collect::<Vec<_>>(),
+            vec![false, false, true, true]
+        );
+
+        a &= &b;
+        assert_eq!(a.iter().collect::<Vec<_>>(), vec![true, false, false, false]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn bitwise_ops_len_mismatch() {
+        let a = VecBool::with_zeros(4);
+        let b = VecBool::with_zeros(5);
+
+        let _ = &a & &b;
+    }
+
+    #[test]
+    fn not_does_not_expose_stale_chunks_past_len() {
+        let mask = !&VecBool::with_zeros(CHUNK_SIZE * 2);
+
+        assert_eq!(mask.iter_ones().count(), mask.len());
+        assert_eq!(mask.iter_ones().max(), Some(mask.len() - 1));
+    }
+
+    #[test]
+    fn set_range() {
+        let mut mask = VecBool::with_zeros(CHUNK_SIZE * 3 + 4);
+
+        mask.set_range(2, CHUNK_SIZE * 2 + 3, true);
+
+        let expected = (0..mask.len())
+            .map(|i| (2..(CHUNK_SIZE * 2 + 3)).contains(&i))
+            .collect::<Vec<_>>();
+        assert_eq!(mask.iter().collect::<Vec<_>>(), expected);
+
+        mask.set_range(2, CHUNK_SIZE * 2 + 3, false);
+        assert_eq!(mask.iter().collect::<Vec<_>>(), vec![false; mask.len()]);
+
+        mask.set_range(5, 5, true);
+        assert_eq!(mask.iter().collect::<Vec<_>>(), vec![false; mask.len()]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn set_range_out_of_bounds() {
+        let mut mask = VecBool::with_zeros(4);
+        mask.set_range(0, 5, true);
+    }
+
+    #[test]
+    fn count_and_iter_ones() {
+        let mut mask = VecBool::new();
+        for value in [true, false, true, true, false, false, true, false, true] {
+            mask.push(value);
+        }
+
+        assert_eq!(mask.count_ones(), 5);
+        assert_eq!(mask.count_zeros(), 4);
+        assert_eq!(mask.iter_ones().collect::<Vec<_>>(), vec![0, 2, 3, 6, 8]);
+
+        mask.pop();
+        assert_eq!(mask.count_ones(), 4);
+        assert_eq!(mask.count_zeros(), 4);
+        assert_eq!(mask.iter_ones().collect::<Vec<_>>(), vec![0, 2, 3, 6]);
+    }
+
     #[test]
     fn with_len() {
         let len = 16;
@@ -204,4 +811,85 @@ mod test {
 
         assert_eq!(mask.get(len), None);
     }
+
+    #[test]
+    fn inline_storage_does_not_allocate() {
+        let mut mask = VecBool::with_zeros(INLINE_CAPACITY);
+        assert!(matches!(mask.repr, Repr::Inline(_)));
+
+        mask.set(0, true);
+        mask.set(INLINE_CAPACITY - 1, true);
+        assert_eq!(mask.get(0), Some(true));
+        assert_eq!(mask.get(INLINE_CAPACITY - 1), Some(true));
+    }
+
+    #[test]
+    fn migrates_between_inline_and_heap() {
+        let mut mask = VecBool::new();
+
+        for _ in 0..INLINE_CAPACITY {
+            mask.push(true);
+            assert!(matches!(mask.repr, Repr::Inline(_)));
+        }
+
+        mask.push(true);
+        assert!(matches!(mask.repr, Repr::Heap(_)));
+        assert_eq!(mask.iter().collect::<Vec<_>>(), vec![true; mask.len()]);
+
+        mask.pop();
+        assert!(matches!(mask.repr, Repr::Inline(_)));
+        assert_eq!(mask.iter().collect::<Vec<_>>(), vec![true; mask.len()]);
+    }
+
+    #[test]
+    fn from_iterator_and_extend() {
+        let values = vec![true, false, true, true, false];
+
+        let mut mask: VecBool = values.iter().copied().collect();
+        assert_eq!(mask.iter().collect::<Vec<_>>(), values);
+
+        mask.extend([false, true]);
+        assert_eq!(
+            mask.iter().collect::<Vec<_>>(),
+            vec![true, false, true, true, false, false, true]
+        );
+    }
+
+    #[test]
+    fn into_iterator() {
+        let values = vec![true, false, true, true, false];
+        let mask: VecBool = values.iter().copied().collect();
+
+        assert_eq!((&mask).into_iter().collect::<Vec<_>>(), values);
+        assert_eq!(mask.into_iter().collect::<Vec<_>>(), values);
+    }
+
+    #[test]
+    fn double_ended_iterator() {
+        let mask: VecBool = [true, false, true, true, false].into_iter().collect();
+
+        let mut iter = mask.iter();
+        assert_eq!(iter.len(), 5);
+        assert_eq!(iter.next(), Some(true));
+        assert_eq!(iter.next_back(), Some(false));
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.rev().collect::<Vec<_>>(), vec![true, true, false]);
+
+        assert_eq!(
+            mask.iter().rev().collect::<Vec<_>>(),
+            vec![false, true, true, false, true]
+        );
+    }
+
+    #[test]
+    fn equality() {
+        let a: VecBool = [true, false, true].into_iter().collect();
+        let b: VecBool = [true, false, true].into_iter().collect();
+        let c: VecBool = [true, false, false].into_iter().collect();
+        let d: VecBool = [true, false].into_iter().collect();
+
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert_ne!(a, d);
+    }
 }